@@ -4,7 +4,11 @@ pub use pallet::*;
 
 pub mod types;
 
-use frame_support::ensure;
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::tokens::{fungibles, DepositConsequence, WithdrawConsequence},
+};
 use types::*;
 
 #[cfg(test)]
@@ -24,6 +28,10 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The maximum number of accounts destroyed in a single `destroy_accounts` call.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -64,6 +72,31 @@ pub mod pallet {
 	/// Nonce for id of the next created asset.
 	pub(super) type Nonce<T: Config> = StorageValue<_, AssetId, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	/// The amount a delegate is allowed to spend on behalf of an owner, for a given asset.
+	pub(super) type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		u128,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn frozen)]
+	/// Whether a given account is frozen out of transfers for a given asset.
+	pub(super) type Frozen<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -99,6 +132,43 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: u128,
 		},
+		/// An approval for a delegate to spend on an owner's behalf has been set or increased.
+		ApprovedTransfer {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: u128,
+		},
+		/// An approval for a delegate has been cancelled.
+		ApprovalCancelled {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		},
+		/// An asset has begun being destroyed.
+		DestructionStarted { asset_id: AssetId },
+		/// A batch of accounts have been destroyed as part of an ongoing destruction.
+		AccountsDestroyed {
+			asset_id: AssetId,
+			remaining: u32,
+		},
+		/// An asset has been destroyed.
+		Destroyed { asset_id: AssetId },
+		/// The management team for an asset has been changed.
+		TeamChanged {
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+		},
+		/// An account has been frozen out of operating on an asset.
+		Frozen { asset_id: AssetId, who: T::AccountId },
+		/// An account has been thawed, allowing it to operate on an asset again.
+		Thawed { asset_id: AssetId, who: T::AccountId },
+		/// An asset has been frozen, blocking transfers out of every account.
+		AssetFrozen { asset_id: AssetId },
+		/// An asset has been thawed, allowing transfers out of accounts again.
+		AssetThawed { asset_id: AssetId },
 	}
 
 	// Errors inform users that something went wrong.
@@ -108,6 +178,16 @@ pub mod pallet {
 		UnknownAssetId,
 		/// The signing account has no permission to do the operation.
 		NoPermission,
+		/// The delegate has no approval, or insufficient approval, to spend this amount.
+		Unapproved,
+		/// The asset is not in the expected status for this operation.
+		IncorrectStatus,
+		/// The asset still has accounts holding a balance.
+		InUse,
+		/// The resulting balance would be non-zero but below the asset's `min_balance`.
+		BelowMinimum,
+		/// The account, or the whole asset, is frozen.
+		Frozen,
 	}
 
 	// Dispatchable functions allow users to interact with the pallet and invoke state changes.
@@ -116,11 +196,11 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::weight(0)]
-		pub fn create(origin: OriginFor<T>) -> DispatchResult {
+		pub fn create(origin: OriginFor<T>, min_balance: u128) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 
 			let id = Self::nonce();
-			let details = AssetDetails::new(origin.clone());
+			let details = AssetDetails::new(origin.clone(), min_balance);
 
 			Asset::<T>::insert(id, details);
 			Nonce::<T>::set(id.saturating_add(1));
@@ -176,105 +256,262 @@ pub mod pallet {
 			let caller = ensure_signed(origin)?;
 			// - Ensure the caller is the asset owner.
 
-			let mut minted_amount = 0;
-			let mut total_supply = 0;
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(details.issuer == caller, Error::<T>::NoPermission);
 
-			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			Self::do_mint(asset_id, &to, amount)
+		}
 
-				ensure!(details.owner == caller, Error::<T>::NoPermission);
+		/// Burn `amount` from `who`'s balance of `asset_id`. Restricted to the asset's admin,
+		/// who may burn from any account.
+		#[pallet::weight(0)]
+		pub fn burn(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			who: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-				let old_supply = details.supply;
-				details.supply = details.supply.saturating_add(amount);
-				minted_amount = details.supply - old_supply;
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(details.admin == caller, Error::<T>::NoPermission);
 
-				total_supply = details.supply;
+			Self::do_burn(asset_id, &who, amount)?;
 
-				Ok(())
-			})?;
+			Ok(())
+		}
 
-			Account::<T>::mutate(asset_id, to.clone(), |balance| {
-				*balance += minted_amount;
-			});
-			// TODO: Deposit a `Minted` event.
-			Self::deposit_event(Event::<T>::Minted {
+		#[pallet::weight(0)]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: u128,
+			to: T::AccountId,
+		) -> DispatchResult {
+			// TODO:
+			// - Ensure the extrinsic origin is a signed transaction.
+			let from = ensure_signed(origin)?;
+
+			let transferred = Self::do_transfer(asset_id, &from, &to, amount)?;
+
+			// - Emit a `Transferred` event.
+
+			Self::deposit_event(Event::<T>::Transferred {
 				asset_id,
-				owner: to,
-				total_supply,
+				from,
+				to,
+				amount: transferred,
 			});
+
 			Ok(())
 		}
 
+		/// Approve `delegate` to spend up to `amount` of `origin`'s holdings of `asset_id`.
+		///
+		/// Successive calls add to any amount already approved for `delegate`.
 		#[pallet::weight(0)]
-		pub fn burn(origin: OriginFor<T>, asset_id: AssetId, amount: u128) -> DispatchResult {
-			// TODO:
-			// - Ensure the extrinsic origin is a signed transaction.
-			let caller = ensure_signed(origin)?;
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			delegate: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
 
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
 			ensure!(
-				Asset::<T>::contains_key(asset_id),
-				Error::<T>::UnknownAssetId
+				details.status == AssetStatus::Live,
+				Error::<T>::IncorrectStatus
+			);
+
+			let mut approved = 0;
+			Approvals::<T>::mutate(
+				asset_id,
+				(owner.clone(), delegate.clone()),
+				|maybe_approved| {
+					approved = maybe_approved.unwrap_or(0).saturating_add(amount);
+					*maybe_approved = Some(approved);
+				},
 			);
 
-			// - Mutate the account balance.
-			let mut burnt_amount = 0;
-			Account::<T>::mutate(asset_id, caller.clone(), |balance| {
-				let old_balance = *balance;
-				*balance = old_balance.saturating_sub(amount);
-				burnt_amount = old_balance - *balance;
+			Self::deposit_event(Event::<T>::ApprovedTransfer {
+				asset_id,
+				owner,
+				delegate,
+				amount: approved,
 			});
 
-			// - Mutate the total supply.
-			let mut total_supply = 0;
+			Ok(())
+		}
 
-			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
-				details.supply = details.supply.saturating_sub(burnt_amount);
+		/// Cancel an approval previously granted to `delegate` for `asset_id`.
+		#[pallet::weight(0)]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			delegate: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
 
-				total_supply = details.supply;
+			Approvals::<T>::take(asset_id, (owner.clone(), delegate.clone()))
+				.ok_or(Error::<T>::Unapproved)?;
 
-				Ok(())
-			})?;
-			// - Emit a `Burned` event.
-			Self::deposit_event(Event::<T>::Burned {
+			Self::deposit_event(Event::<T>::ApprovalCancelled {
 				asset_id,
-				owner: caller,
-				total_supply,
+				owner,
+				delegate,
 			});
+
 			Ok(())
 		}
 
+		/// Transfer up to `amount` of `owner`'s holdings of `asset_id` to `destination`, spending
+		/// from the allowance the caller was given via `approve_transfer`.
 		#[pallet::weight(0)]
-		pub fn transfer(
+		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			asset_id: AssetId,
+			owner: T::AccountId,
+			destination: T::AccountId,
 			amount: u128,
-			to: T::AccountId,
 		) -> DispatchResult {
-			// TODO:
-			// - Ensure the extrinsic origin is a signed transaction.
-			let from = ensure_signed(origin)?;
-			// - Mutate both account balances.
-			let mut transferred = 0;
+			let delegate = ensure_signed(origin)?;
 
+			let approved = Approvals::<T>::get(asset_id, (owner.clone(), delegate.clone()))
+				.ok_or(Error::<T>::Unapproved)?;
+			ensure!(approved >= amount, Error::<T>::Unapproved);
+
+			let transferred = Self::do_transfer(asset_id, &owner, &destination, amount)?;
+
+			// Spend the allowance by the amount actually moved, not the amount requested:
+			// `do_transfer` silently caps at `owner`'s balance, and charging the delegate's
+			// full request would burn allowance for tokens that never moved.
+			let remaining = approved - transferred;
+			if remaining > 0 {
+				Approvals::<T>::insert(asset_id, (owner.clone(), delegate), remaining);
+			} else {
+				Approvals::<T>::remove(asset_id, (owner.clone(), delegate));
+			}
+
+			Self::deposit_event(Event::<T>::Transferred {
+				asset_id,
+				from: owner,
+				to: destination,
+				amount: transferred,
+			});
+
+			Ok(())
+		}
+
+		/// Start the destruction of an asset, owner only. No further `mint`, `transfer` or
+		/// `approve_transfer` calls will succeed until the asset is fully destroyed.
+		#[pallet::weight(0)]
+		pub fn start_destroy(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+
+				ensure!(details.owner == caller, Error::<T>::NoPermission);
+				ensure!(
+					details.status == AssetStatus::Live,
+					Error::<T>::IncorrectStatus
+				);
+
+				details.status = AssetStatus::Destroying;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::DestructionStarted { asset_id });
+
+			Ok(())
+		}
+
+		/// Destroy up to `RemoveItemsLimit` accounts of an asset that is being destroyed. Call
+		/// repeatedly until `AccountsDestroyed` reports zero remaining, then call
+		/// `finish_destroy`.
+		#[pallet::weight(0)]
+		pub fn destroy_accounts(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
 			ensure!(
-				Asset::<T>::contains_key(asset_id) == true,
-				Error::<T>::UnknownAssetId
+				details.status == AssetStatus::Destroying,
+				Error::<T>::IncorrectStatus
 			);
 
-			Account::<T>::mutate(asset_id, from.clone(), |balance| {
-				let old_balance = *balance;
-				*balance = old_balance.saturating_sub(amount);
-				transferred = old_balance - *balance;
+			let mut removed_supply: u128 = 0;
+			let mut removed_accounts: u32 = 0;
+			let limit = T::RemoveItemsLimit::get() as usize;
+
+			for (_, balance) in Account::<T>::drain_prefix(asset_id).take(limit) {
+				removed_supply = removed_supply.saturating_add(balance);
+				removed_accounts = removed_accounts.saturating_add(1);
+			}
+
+			Asset::<T>::mutate(asset_id, |maybe_details| {
+				if let Some(details) = maybe_details {
+					details.supply = details.supply.saturating_sub(removed_supply);
+					details.accounts = details.accounts.saturating_sub(removed_accounts);
+				}
 			});
 
-			Account::<T>::mutate(asset_id, to.clone(), |balance| {
-				let old_balance = *balance;
-				*balance = old_balance.saturating_add(transferred);
-				transferred = *balance - old_balance;
+			let remaining = Account::<T>::iter_prefix(asset_id).count() as u32;
+
+			Self::deposit_event(Event::<T>::AccountsDestroyed {
+				asset_id,
+				remaining,
 			});
 
-			// - Emit a `Transferred` event.
+			Ok(())
+		}
+
+		/// Finish the destruction of an asset once all of its accounts have been destroyed,
+		/// removing its `Asset`, `Metadata`, `Approvals` and `Frozen` entries. `asset_id` is
+		/// never reused, so these would otherwise be orphaned in storage forever.
+		#[pallet::weight(0)]
+		pub fn finish_destroy(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Destroying,
+				Error::<T>::IncorrectStatus
+			);
+			ensure!(
+				Account::<T>::iter_prefix(asset_id).next().is_none(),
+				Error::<T>::InUse
+			);
+
+			Approvals::<T>::remove_prefix(asset_id, None);
+			Frozen::<T>::remove_prefix(asset_id, None);
+			Asset::<T>::remove(asset_id);
+			Metadata::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::Destroyed { asset_id });
+
+			Ok(())
+		}
+
+		/// Force a transfer of `amount` between two accounts, owner and issuer permissions
+		/// notwithstanding. Bypasses any freeze on `from` or the asset, so the admin can move
+		/// funds out of a frozen account, e.g. for compliance/seizure. Restricted to the
+		/// asset's admin.
+		#[pallet::weight(0)]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(details.admin == caller, Error::<T>::NoPermission);
+
+			let transferred = Self::do_force_transfer(asset_id, &from, &to, amount)?;
 
 			Self::deposit_event(Event::<T>::Transferred {
 				asset_id,
@@ -285,6 +522,107 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Change the issuer, admin and freezer of an asset. Owner only.
+		#[pallet::weight(0)]
+		pub fn set_team(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				ensure!(details.owner == caller, Error::<T>::NoPermission);
+
+				details.issuer = issuer.clone();
+				details.admin = admin.clone();
+				details.freezer = freezer.clone();
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TeamChanged {
+				asset_id,
+				issuer,
+				admin,
+				freezer,
+			});
+
+			Ok(())
+		}
+
+		/// Freeze `who` out of transfers of `asset_id`. Restricted to the asset's freezer.
+		#[pallet::weight(0)]
+		pub fn freeze(origin: OriginFor<T>, asset_id: AssetId, who: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(details.freezer == caller, Error::<T>::NoPermission);
+
+			Frozen::<T>::insert(asset_id, &who, true);
+
+			Self::deposit_event(Event::<T>::Frozen { asset_id, who });
+
+			Ok(())
+		}
+
+		/// Thaw a previously frozen account for `asset_id`. Restricted to the asset's freezer.
+		#[pallet::weight(0)]
+		pub fn thaw(origin: OriginFor<T>, asset_id: AssetId, who: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(details.freezer == caller, Error::<T>::NoPermission);
+
+			Frozen::<T>::remove(asset_id, &who);
+
+			Self::deposit_event(Event::<T>::Thawed { asset_id, who });
+
+			Ok(())
+		}
+
+		/// Freeze the whole asset, blocking transfers out of every account. Restricted to the
+		/// asset's freezer.
+		#[pallet::weight(0)]
+		pub fn freeze_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				ensure!(details.freezer == caller, Error::<T>::NoPermission);
+
+				details.is_frozen = true;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::AssetFrozen { asset_id });
+
+			Ok(())
+		}
+
+		/// Thaw a previously frozen asset. Restricted to the asset's freezer.
+		#[pallet::weight(0)]
+		pub fn thaw_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				ensure!(details.freezer == caller, Error::<T>::NoPermission);
+
+				details.is_frozen = false;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::AssetThawed { asset_id });
+
+			Ok(())
+		}
 	}
 }
 
@@ -298,4 +636,285 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Move `amount` from `from`'s to `to`'s balance of `asset_id`, returning the amount
+	/// actually moved. Shared by `transfer` and `transfer_approved`.
+	///
+	/// See `do_force_transfer` for the variant used by `force_transfer` that bypasses the
+	/// `Frozen` check.
+	///
+	/// Rejects the transfer with `BelowMinimum` if it would leave either account with a
+	/// non-zero balance under the asset's `min_balance`; an account whose balance drops to
+	/// exactly zero is reaped instead.
+	fn do_transfer(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Result<u128, DispatchError> {
+		Self::do_transfer_inner(asset_id, from, to, amount, false, false)
+	}
+
+	/// As `do_transfer`, but skips the `Frozen` check. Used by `force_transfer`, which exists
+	/// precisely so the admin can move funds out of an account (or asset) that the freezer has
+	/// already frozen, e.g. for compliance/seizure.
+	fn do_force_transfer(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Result<u128, DispatchError> {
+		Self::do_transfer_inner(asset_id, from, to, amount, true, false)
+	}
+
+	/// As `do_transfer`, but rejects the transfer with `BelowMinimum` rather than reaping
+	/// `from` when `keep_alive` is set. Used by the `fungibles::Transfer` impl, which promises
+	/// callers that `keep_alive: true` protects the source account from being removed.
+	fn do_transfer_inner(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+		force: bool,
+		keep_alive: bool,
+	) -> Result<u128, DispatchError> {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		let min_balance = details.min_balance;
+
+		ensure!(
+			details.status == AssetStatus::Live,
+			Error::<T>::IncorrectStatus
+		);
+		ensure!(
+			force || (!details.is_frozen && !Self::frozen(asset_id, from)),
+			Error::<T>::Frozen
+		);
+
+		let old_from_balance = Self::account(asset_id, from);
+		let new_from_balance = old_from_balance.saturating_sub(amount);
+		ensure!(
+			new_from_balance == 0 || new_from_balance >= min_balance,
+			Error::<T>::BelowMinimum
+		);
+		ensure!(!keep_alive || new_from_balance > 0, Error::<T>::BelowMinimum);
+
+		let transferred = old_from_balance - new_from_balance;
+
+		// On a transfer to self, `to` starts from the already-debited balance.
+		let old_to_balance = if from == to { new_from_balance } else { Self::account(asset_id, to) };
+		let new_to_balance = old_to_balance.saturating_add(transferred);
+		ensure!(
+			new_to_balance == 0 || new_to_balance >= min_balance,
+			Error::<T>::BelowMinimum
+		);
+
+		if new_from_balance == 0 {
+			Account::<T>::remove(asset_id, from);
+		} else {
+			Account::<T>::insert(asset_id, from, new_from_balance);
+		}
+		Account::<T>::insert(asset_id, to, new_to_balance);
+
+		Asset::<T>::mutate(asset_id, |maybe_details| {
+			if let Some(details) = maybe_details {
+				if new_from_balance == 0 && old_from_balance > 0 {
+					details.accounts = details.accounts.saturating_sub(1);
+				}
+				if old_to_balance == 0 && new_to_balance > 0 {
+					details.accounts = details.accounts.saturating_add(1);
+				}
+			}
+		});
+
+		Ok(transferred)
+	}
+
+	/// Credit `amount` to `to`'s balance of `asset_id` and increase its supply accordingly.
+	/// Shared by the `mint` call and the `fungibles::Mutate` impl.
+	fn do_mint(asset_id: AssetId, to: &T::AccountId, amount: u128) -> DispatchResult {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		let min_balance = details.min_balance;
+		ensure!(
+			details.status == AssetStatus::Live,
+			Error::<T>::IncorrectStatus
+		);
+
+		let old_balance = Self::account(asset_id, to);
+		let new_balance = old_balance.saturating_add(amount);
+		ensure!(
+			new_balance == 0 || new_balance >= min_balance,
+			Error::<T>::BelowMinimum
+		);
+
+		let mut minted_amount = 0;
+		let mut total_supply = 0;
+
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+
+			let old_supply = details.supply;
+			details.supply = details.supply.saturating_add(amount);
+			minted_amount = details.supply - old_supply;
+
+			total_supply = details.supply;
+
+			if old_balance == 0 && minted_amount > 0 {
+				details.accounts = details.accounts.saturating_add(1);
+			}
+
+			Ok(())
+		})?;
+
+		Account::<T>::mutate(asset_id, to, |balance| {
+			*balance += minted_amount;
+		});
+
+		Self::deposit_event(Event::<T>::Minted {
+			asset_id,
+			owner: to.clone(),
+			total_supply,
+		});
+
+		Ok(())
+	}
+
+	/// Debit up to `amount` from `who`'s balance of `asset_id`, reaping the account if it
+	/// drops to zero, and decrease its supply accordingly. Returns the amount actually
+	/// removed. Shared by the `burn` call and the `fungibles::Mutate` impl.
+	fn do_burn(asset_id: AssetId, who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+		let min_balance = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?.min_balance;
+
+		let old_balance = Self::account(asset_id, who);
+		let new_balance = old_balance.saturating_sub(amount);
+		ensure!(
+			new_balance == 0 || new_balance >= min_balance,
+			Error::<T>::BelowMinimum
+		);
+
+		let burnt_amount = old_balance - new_balance;
+		if new_balance == 0 {
+			Account::<T>::remove(asset_id, who);
+		} else {
+			Account::<T>::insert(asset_id, who, new_balance);
+		}
+
+		let mut total_supply = 0;
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			details.supply = details.supply.saturating_sub(burnt_amount);
+
+			total_supply = details.supply;
+
+			if new_balance == 0 && old_balance > 0 {
+				details.accounts = details.accounts.saturating_sub(1);
+			}
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::Burned {
+			asset_id,
+			owner: who.clone(),
+			total_supply,
+		});
+
+		Ok(burnt_amount)
+	}
+}
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = AssetId;
+	type Balance = u128;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		Self::asset(asset)
+			.map(|details| details.supply)
+			.unwrap_or_default()
+	}
+
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		Self::asset(asset)
+			.map(|details| details.min_balance)
+			.unwrap_or_default()
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Self::account(asset, who)
+	}
+
+	fn reducible_balance(asset: Self::AssetId, who: &T::AccountId, keep_alive: bool) -> Self::Balance {
+		let balance = Self::balance(asset, who);
+		if keep_alive {
+			balance.saturating_sub(Self::minimum_balance(asset))
+		} else {
+			balance
+		}
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DepositConsequence {
+		let details = match Self::asset(asset) {
+			Some(details) => details,
+			None => return DepositConsequence::UnknownAsset,
+		};
+
+		let new_balance = Self::balance(asset, who).saturating_add(amount);
+		if new_balance > 0 && new_balance < details.min_balance {
+			DepositConsequence::BelowMinimum
+		} else {
+			DepositConsequence::Success
+		}
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		let details = match Self::asset(asset) {
+			Some(details) => details,
+			None => return WithdrawConsequence::UnknownAsset,
+		};
+
+		let balance = Self::balance(asset, who);
+		if amount > balance {
+			return WithdrawConsequence::NoFunds;
+		}
+
+		let new_balance = balance - amount;
+		if new_balance > 0 && new_balance < details.min_balance {
+			WithdrawConsequence::WouldDie
+		} else {
+			WithdrawConsequence::Success
+		}
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::do_mint(asset, who, amount)
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_burn(asset, who, amount)
+	}
+}
+
+impl<T: Config> fungibles::Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_transfer_inner(asset, source, dest, amount, false, keep_alive)
+	}
 }
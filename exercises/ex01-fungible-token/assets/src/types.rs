@@ -0,0 +1,63 @@
+use frame_support::pallet_prelude::*;
+
+/// The identifier for an asset class.
+pub type AssetId = u32;
+
+/// The status of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetStatus {
+	/// The asset is live and open to ordinary operations.
+	Live,
+	/// The asset is in the process of being destroyed, via repeated calls to
+	/// `destroy_accounts`.
+	Destroying,
+}
+
+/// Details of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetDetails<AccountId> {
+	/// The account that created the asset and is allowed to administer it.
+	pub owner: AccountId,
+	/// Can `mint` new tokens.
+	pub issuer: AccountId,
+	/// Can `burn` tokens from any account and force transfers between accounts.
+	pub admin: AccountId,
+	/// Can freeze and thaw accounts and the asset as a whole.
+	pub freezer: AccountId,
+	/// The total supply across all accounts.
+	pub supply: u128,
+	/// The minimum balance an account is allowed to hold. Accounts whose balance would drop
+	/// below this, but stay above zero, are rejected; accounts that drop to exactly zero are
+	/// reaped.
+	pub min_balance: u128,
+	/// The number of accounts currently holding a non-zero balance of this asset.
+	pub accounts: u32,
+	/// Whether the asset is live or being destroyed.
+	pub status: AssetStatus,
+	/// Whether the whole asset is frozen, blocking transfers out of every account.
+	pub is_frozen: bool,
+}
+
+impl<AccountId: Clone> AssetDetails<AccountId> {
+	pub fn new(owner: AccountId, min_balance: u128) -> Self {
+		Self {
+			issuer: owner.clone(),
+			admin: owner.clone(),
+			freezer: owner.clone(),
+			owner,
+			supply: 0,
+			min_balance,
+			accounts: 0,
+			status: AssetStatus::Live,
+			is_frozen: false,
+		}
+	}
+}
+
+/// Metadata for an asset class, as specified by its owner.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxLength))]
+pub struct AssetMetadata<MaxLength: Get<u32>> {
+	pub name: BoundedVec<u8, MaxLength>,
+	pub symbol: BoundedVec<u8, MaxLength>,
+}
@@ -17,6 +17,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Hash, IdentifyAccount, Verify};
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + scale_info::TypeInfo {
@@ -24,6 +25,18 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The signature type used to authenticate pre-signed mints.
+		type Signature: Verify<Signer = Self::Signer> + Encode + Decode + TypeInfo;
+
+		/// The signer type recoverable from `Signature`, identifying the authorizing account.
+		type Signer: IdentifyAccount<AccountId = Self::AccountId>
+			+ Encode
+			+ Decode
+			+ TypeInfo
+			+ Clone
+			+ PartialEq
+			+ core::fmt::Debug;
 	}
 
 	#[pallet::pallet]
@@ -53,6 +66,16 @@ pub mod pallet {
 	/// Nonce for id of the next created asset
 	pub(super) type Nonce<T: Config> = StorageValue<_, UniqueAssetId, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn authorizer)]
+	/// The account whose signature is accepted by `mint_pre_signed`.
+	pub(super) type Authorizer<T: Config> = StorageValue<_, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn consumed)]
+	/// Pre-signed mints that have already been redeemed, to prevent replay.
+	pub(super) type Consumed<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -74,6 +97,12 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: u128,
 		},
+		/// A new unique asset was minted from a pre-signed authorization
+		PreSignedMinted {
+			asset_id: UniqueAssetId,
+			signer: T::AccountId,
+			mint_to: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -84,6 +113,14 @@ pub mod pallet {
 		NotOwned,
 		/// Supply must be positive
 		NoSupply,
+		/// The pre-signed mint's deadline has passed
+		DeadlineExpired,
+		/// The signature does not match the signer for the given mint data
+		WrongSignature,
+		/// The signer is not authorized to sign off-chain mints
+		NotAuthorized,
+		/// This pre-signed mint has already been redeemed
+		AlreadyUsed,
 	}
 
 	#[pallet::call]
@@ -202,5 +239,74 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the account whose signature `mint_pre_signed` accepts. Root only.
+		#[pallet::weight(0)]
+		pub fn set_authorizer(origin: OriginFor<T>, authorizer: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Authorizer::<T>::put(authorizer);
+
+			Ok(())
+		}
+
+		/// Mint a new unique asset from a mint authorized off-chain by `signer`, letting the
+		/// extrinsic's signed origin (e.g. the recipient) pay the fee instead of `signer`.
+		#[pallet::weight(0)]
+		pub fn mint_pre_signed(
+			origin: OriginFor<T>,
+			mint_data: PreSignedMint<T::AccountId, T::MaxLength, T::BlockNumber>,
+			signature: T::Signature,
+			signer: T::AccountId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			ensure!(mint_data.supply > 0, Error::<T>::NoSupply);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= mint_data.deadline, Error::<T>::DeadlineExpired);
+
+			let encoded = mint_data.encode();
+			ensure!(
+				signature.verify(&encoded[..], &signer),
+				Error::<T>::WrongSignature
+			);
+
+			ensure!(
+				Self::authorizer() == Some(signer.clone()),
+				Error::<T>::NotAuthorized
+			);
+
+			let mint_hash = T::Hashing::hash_of(&(&signer, &mint_data));
+			ensure!(
+				!Consumed::<T>::contains_key(mint_hash),
+				Error::<T>::AlreadyUsed
+			);
+			Consumed::<T>::insert(mint_hash, ());
+
+			let asset_id = Self::nonce();
+			Nonce::<T>::set(asset_id + 1);
+
+			let details = UniqueAssetDetails::new(
+				signer.clone(),
+				mint_data.asset_id_metadata,
+				mint_data.supply,
+			);
+
+			UniqueAsset::<T>::insert(asset_id, details);
+			Account::<T>::insert(asset_id, mint_data.mint_to.clone(), mint_data.supply);
+
+			Self::deposit_event(Event::<T>::Created {
+				creator: signer.clone(),
+				asset_id,
+			});
+			Self::deposit_event(Event::<T>::PreSignedMinted {
+				asset_id,
+				signer,
+				mint_to: mint_data.mint_to,
+			});
+
+			Ok(())
+		}
 	}
 }
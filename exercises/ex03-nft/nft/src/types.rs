@@ -0,0 +1,43 @@
+use frame_support::pallet_prelude::*;
+
+use crate::Config;
+
+/// The identifier for a unique asset.
+pub type UniqueAssetId = u32;
+
+/// Details of a unique asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T, MaxLength))]
+pub struct UniqueAssetDetails<T: Config, MaxLength: Get<u32>> {
+	/// The account that minted the asset.
+	pub creator: T::AccountId,
+	/// Arbitrary metadata describing the asset.
+	pub metadata: BoundedVec<u8, MaxLength>,
+	/// The total supply across all accounts.
+	pub supply: u128,
+}
+
+impl<T: Config, MaxLength: Get<u32>> UniqueAssetDetails<T, MaxLength> {
+	pub fn new(creator: T::AccountId, metadata: BoundedVec<u8, MaxLength>, supply: u128) -> Self {
+		Self {
+			creator,
+			metadata,
+			supply,
+		}
+	}
+}
+
+/// The data a collection authorizer signs off-chain to let someone else submit the mint
+/// extrinsic (and pay its fee) on their behalf.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxLength))]
+pub struct PreSignedMint<AccountId, MaxLength: Get<u32>, BlockNumber> {
+	/// Metadata for the asset to be minted.
+	pub asset_id_metadata: BoundedVec<u8, MaxLength>,
+	/// The supply of the asset to be minted.
+	pub supply: u128,
+	/// The block number after which this authorization is no longer valid.
+	pub deadline: BlockNumber,
+	/// The account the minted asset is credited to.
+	pub mint_to: AccountId,
+}